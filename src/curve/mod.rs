@@ -0,0 +1,299 @@
+//===================================================================================================================================================================================//
+//
+//  /$$$$$$$                        /$$            /$$$$$$  /$$$$$$$
+// | $$__  $$                      | $$           /$$__  $$| $$__  $$
+// | $$  \ $$  /$$$$$$   /$$$$$$$ /$$$$$$        |__/  \ $$| $$  \ $$
+// | $$$$$$$/ /$$__  $$ /$$_____/|_  $$_/          /$$$$$$/| $$  | $$
+// | $$__  $$| $$$$$$$$| $$        | $$           /$$____/ | $$  | $$
+// | $$  \ $$| $$_____/| $$        | $$ /$$      | $$      | $$  | $$
+// | $$  | $$|  $$$$$$$|  $$$$$$$  |  $$$$/      | $$$$$$$$| $$$$$$$/
+// |__/  |__/ \_______/ \_______/   \___/        |________/|_______/
+//
+//===================================================================================================================================================================================//
+
+//?
+//? Created by LunaticWyrm467 and others.
+//?
+//? All code is licensed under the MIT license.
+//? Feel free to reproduce, modify, and do whatever.
+//?
+
+//!
+//! Contains 2D curve primitives built on top of the per-axis Bézier math already defined on
+//! `SignedScalar`. `CubicBezier2` turns that scalar math into a usable path type with
+//! subdivision, adaptive flattening, and bounding-box support.
+//!
+
+#[cfg(feature = "libm")]
+use alloc::{ vec, vec::Vec };
+
+use crate::scalar::FloatScalar;
+use crate::scalar::ops;
+use crate::vector::Vec2;
+use crate::rect::{ Rect, Rect2 };
+
+
+/*
+    Cubic Bezier 2D
+        Implementation
+*/
+
+
+/// A cubic Bézier curve in 2D space, defined by a `start` point, two control points, and an
+/// `end` point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CubicBezier2<T: FloatScalar> {
+    pub start:     Vec2<T>,
+    pub control_1: Vec2<T>,
+    pub control_2: Vec2<T>,
+    pub end:       Vec2<T>
+}
+
+impl <T: FloatScalar> CubicBezier2<T> {
+
+    /// Creates a new cubic Bézier curve from a start point, two control points, and an end point.
+    pub fn new(start: Vec2<T>, control_1: Vec2<T>, control_2: Vec2<T>, end: Vec2<T>) -> CubicBezier2<T> {
+        CubicBezier2 { start, control_1, control_2, end }
+    }
+
+    /// Samples the point on the curve at position `t`, by sampling each axis independently via
+    /// `SignedScalar::bezier_sample`.
+    pub fn sample(&self, t: T) -> Vec2<T> {
+        let x: T = self.start.x().bezier_sample(self.control_1.x(), self.control_2.x(), self.end.x(), t);
+        let y: T = self.start.y().bezier_sample(self.control_1.y(), self.control_2.y(), self.end.y(), t);
+        Vec2(x, y)
+    }
+
+    /// Samples the tangent (derivative) of the curve at position `t`, by sampling each axis
+    /// independently via `SignedScalar::bezier_derivative`.
+    pub fn tangent(&self, t: T) -> Vec2<T> {
+        let x: T = self.start.x().bezier_derivative(self.control_1.x(), self.control_2.x(), self.end.x(), t);
+        let y: T = self.start.y().bezier_derivative(self.control_1.y(), self.control_2.y(), self.end.y(), t);
+        Vec2(x, y)
+    }
+
+    /// Splits this curve at parameter `t` via De Casteljau's algorithm, returning the two
+    /// resulting curves in order.
+    pub fn subdivide(&self, t: T) -> (CubicBezier2<T>, CubicBezier2<T>) {
+        let p01:  Vec2<T> = Self::lerp(self.start.to_owned(), self.control_1.to_owned(), t);
+        let p12:  Vec2<T> = Self::lerp(self.control_1.to_owned(), self.control_2.to_owned(), t);
+        let p23:  Vec2<T> = Self::lerp(self.control_2.to_owned(), self.end.to_owned(), t);
+        let p012: Vec2<T> = Self::lerp(p01.to_owned(), p12.to_owned(), t);
+        let p123: Vec2<T> = Self::lerp(p12.to_owned(), p23.to_owned(), t);
+        let p:    Vec2<T> = Self::lerp(p012.to_owned(), p123.to_owned(), t);
+
+        (
+            CubicBezier2::new(self.start.to_owned(), p01, p012, p.to_owned()),
+            CubicBezier2::new(p, p123, p23, self.end.to_owned())
+        )
+    }
+
+    /// Flattens the curve into a sequence of points such that no point deviates from a straight
+    /// line approximation by more than `tolerance`.
+    ///
+    /// Recursively subdivides the curve, testing flatness by measuring the perpendicular distance
+    /// of both control points from the `start`-`end` baseline.
+    pub fn flatten(&self, tolerance: T) -> Vec<Vec2<T>> {
+        let mut points: Vec<Vec2<T>> = vec![self.start.to_owned()];
+        self.flatten_into(tolerance, &mut points);
+        points
+    }
+
+    /// Returns the axis-aligned bounding rectangle of the curve by solving the derivative's
+    /// roots per axis and encompassing the resulting extrema alongside both endpoints.
+    pub fn bounding_rect(&self) -> Rect2<T> {
+        let mut candidates: Vec<Vec2<T>> = vec![self.start.to_owned(), self.end.to_owned()];
+        for t in Self::axis_extrema(self.start.x(), self.control_1.x(), self.control_2.x(), self.end.x())
+            .into_iter()
+            .chain(Self::axis_extrema(self.start.y(), self.control_1.y(), self.control_2.y(), self.end.y()))
+        {
+            candidates.push(self.sample(t));
+        }
+
+        Rect2::encompass_points(&candidates)
+    }
+
+    /// Recursive helper for `flatten` that appends flattened points to `points`.
+    fn flatten_into(&self, tolerance: T, points: &mut Vec<Vec2<T>>) {
+        if self.is_flat(tolerance) {
+            points.push(self.end.to_owned());
+            return;
+        }
+
+        let (lhs, rhs): (CubicBezier2<T>, CubicBezier2<T>) = self.subdivide(T::from(0.5).unwrap());
+        lhs.flatten_into(tolerance, points);
+        rhs.flatten_into(tolerance, points);
+    }
+
+    /// Returns whether both control points lie within `tolerance` of the `start`-`end` baseline.
+    ///
+    /// When `start` and `end` coincide, there is no baseline direction to project onto, so this
+    /// falls back to the control points' straight-line distance from `start` instead of
+    /// short-circuiting to flat — otherwise a loop whose endpoints happen to coincide would
+    /// collapse to a single point.
+    fn is_flat(&self, tolerance: T) -> bool {
+        let baseline:     Vec2<T> = self.end.to_owned() - self.start.to_owned();
+        let baseline_len: T       = ops::sqrt(baseline.x() * baseline.x() + baseline.y() * baseline.y());
+
+        let offset_1: Vec2<T> = self.control_1.to_owned() - self.start.to_owned();
+        let offset_2: Vec2<T> = self.control_2.to_owned() - self.start.to_owned();
+
+        if baseline_len == T::zero() {
+            return Self::distance(&offset_1) <= tolerance && Self::distance(&offset_2) <= tolerance;
+        }
+
+        Self::perpendicular_distance(&baseline, baseline_len, &offset_1) <= tolerance
+            && Self::perpendicular_distance(&baseline, baseline_len, &offset_2) <= tolerance
+    }
+
+    /// Computes the perpendicular distance of `offset` from the `baseline` direction via the 2D
+    /// cross product, normalized by the baseline's length.
+    fn perpendicular_distance(baseline: &Vec2<T>, baseline_len: T, offset: &Vec2<T>) -> T {
+        let cross: T = baseline.x() * offset.y() - baseline.y() * offset.x();
+        ops::abs(cross / baseline_len)
+    }
+
+    /// Computes the Euclidean distance of `offset` from the origin.
+    fn distance(offset: &Vec2<T>) -> T {
+        ops::sqrt(offset.x() * offset.x() + offset.y() * offset.y())
+    }
+
+    /// Solves for the roots of the derivative of a single-axis cubic Bézier, returning the `t`
+    /// values within `(0, 1)` at which that axis reaches a local extremum.
+    fn axis_extrema(p0: T, p1: T, p2: T, p3: T) -> Vec<T> {
+
+        // The derivative of a cubic Bézier is a quadratic `a*t^2 + b*t + c`.
+        let a_vec: T = p1 - p0;
+        let b_vec: T = p2 - p1;
+        let c_vec: T = p3 - p2;
+
+        let t_2: T = T::from(2).unwrap();
+        let t_3: T = T::from(3).unwrap();
+        let t_4: T = T::from(4).unwrap();
+        let t_6: T = T::from(6).unwrap();
+
+        let a: T = t_3 * (a_vec - t_2 * b_vec + c_vec);
+        let b: T = t_6 * (b_vec - a_vec);
+        let c: T = t_3 * a_vec;
+
+        let mut roots: Vec<T> = Vec::new();
+        if ops::abs(a) > T::epsilon() {
+            let discriminant: T = b * b - t_4 * a * c;
+            if discriminant >= T::zero() {
+                let sqrt_d: T = ops::sqrt(discriminant);
+                roots.push((-b + sqrt_d) / (t_2 * a));
+                roots.push((-b - sqrt_d) / (t_2 * a));
+            }
+        } else if ops::abs(b) > T::epsilon() {
+            roots.push(-c / b);
+        }
+
+        roots.into_iter().filter(|t| *t > T::zero() && *t < T::one()).collect()
+    }
+
+    /// Componentwise linear interpolation between two points, reusing `Scalar::lerp` per axis.
+    fn lerp(a: Vec2<T>, b: Vec2<T>, t: T) -> Vec2<T> {
+        Vec2(a.x().lerp(b.x(), t), a.y().lerp(b.y(), t))
+    }
+}
+
+
+/*
+    Unit
+        Tests
+*/
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn line() -> CubicBezier2<f64> {
+
+        // A perfectly straight curve: control points lie on the start-end baseline.
+        CubicBezier2::new(Vec2(0.0, 0.0), Vec2(1.0, 0.0), Vec2(2.0, 0.0), Vec2(3.0, 0.0))
+    }
+
+    #[test]
+    fn sample_returns_endpoints_at_t_zero_and_one() {
+        let curve: CubicBezier2<f64> = line();
+
+        assert_relative_eq!(curve.sample(0.0), curve.start);
+        assert_relative_eq!(curve.sample(1.0), curve.end);
+    }
+
+    #[test]
+    fn tangent_of_a_straight_line_points_along_the_baseline() {
+        let curve:   CubicBezier2<f64> = line();
+        let tangent: Vec2<f64>         = curve.tangent(0.5);
+
+        assert_relative_eq!(tangent.y(), 0.0);
+        assert!(tangent.x() > 0.0);
+    }
+
+    #[test]
+    fn subdivide_splits_a_curve_at_its_shared_endpoint() {
+        let curve:       CubicBezier2<f64> = line();
+        let (lhs, rhs):  (CubicBezier2<f64>, CubicBezier2<f64>) = curve.subdivide(0.5);
+
+        assert_relative_eq!(lhs.start, curve.start);
+        assert_relative_eq!(lhs.end, rhs.start);
+        assert_relative_eq!(rhs.end, curve.end);
+        assert_relative_eq!(lhs.end, curve.sample(0.5));
+    }
+
+    #[test]
+    fn flatten_of_a_straight_line_needs_only_its_endpoints() {
+        let curve:  CubicBezier2<f64>  = line();
+        let points: Vec<Vec2<f64>>     = curve.flatten(1e-6);
+
+        assert_eq!(points.len(), 2);
+        assert_relative_eq!(points[0], curve.start);
+        assert_relative_eq!(points[points.len() - 1], curve.end);
+    }
+
+    #[test]
+    fn flatten_of_a_bulging_curve_produces_intermediate_points() {
+        let curve: CubicBezier2<f64> = CubicBezier2::new(
+            Vec2(0.0, 0.0), Vec2(0.0, 10.0), Vec2(10.0, 10.0), Vec2(10.0, 0.0)
+        );
+        let points: Vec<Vec2<f64>> = curve.flatten(1e-3);
+
+        assert!(points.len() > 2);
+    }
+
+    #[test]
+    fn flatten_does_not_collapse_a_loop_with_coincident_endpoints() {
+
+        // `start` and `end` coincide, but the control points bulge far away: this must not be
+        // mistaken for a degenerate (zero-length) flat curve.
+        let curve: CubicBezier2<f64> = CubicBezier2::new(
+            Vec2(0.0, 0.0), Vec2(10.0, 10.0), Vec2(-10.0, 10.0), Vec2(0.0, 0.0)
+        );
+        let points: Vec<Vec2<f64>> = curve.flatten(1e-3);
+
+        assert!(points.len() > 2);
+    }
+
+    #[test]
+    fn bounding_rect_encompasses_the_endpoints_and_the_curve_bulge() {
+        let curve: CubicBezier2<f64> = CubicBezier2::new(
+            Vec2(0.0, 0.0), Vec2(0.0, 10.0), Vec2(10.0, 10.0), Vec2(10.0, 0.0)
+        );
+        let bounds: Rect2<f64> = curve.bounding_rect();
+
+        assert!(bounds.x() <= 0.0);
+        assert!(bounds.y() <= 0.0);
+        assert!(bounds.x() + bounds.width() >= 10.0);
+        assert!(bounds.y() + bounds.height() > 0.0);
+    }
+
+    #[test]
+    fn bounding_rect_of_a_straight_line_matches_its_endpoints() {
+        let curve:  CubicBezier2<f64> = line();
+        let bounds: Rect2<f64>        = curve.bounding_rect();
+
+        assert_relative_eq!(bounds, Rect2::encompass_points(&vec![curve.start, curve.end]));
+    }
+}
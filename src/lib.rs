@@ -0,0 +1,34 @@
+//===================================================================================================================================================================================//
+//
+//  /$$$$$$$                        /$$            /$$$$$$  /$$$$$$$
+// | $$__  $$                      | $$           /$$__  $$| $$__  $$
+// | $$  \ $$  /$$$$$$   /$$$$$$$ /$$$$$$        |__/  \ $$| $$  \ $$
+// | $$$$$$$/ /$$__  $$ /$$_____/|_  $$_/          /$$$$$$/| $$  | $$
+// | $$__  $$| $$$$$$$$| $$        | $$           /$$____/ | $$  | $$
+// | $$  \ $$| $$_____/| $$        | $$ /$$      | $$      | $$  | $$
+// | $$  | $$|  $$$$$$$|  $$$$$$$  |  $$$$/      | $$$$$$$$| $$$$$$$/
+// |__/  |__/ \_______/ \_______/   \___/        |________/|_______/
+//
+//===================================================================================================================================================================================//
+
+//?
+//? Created by LunaticWyrm467 and others.
+//?
+//? All code is licensed under the MIT license.
+//? Feel free to reproduce, modify, and do whatever.
+//?
+
+//!
+//! SwiftVec is a generic, scalar-first vector and geometry library.
+//!
+
+#![cfg_attr(all(feature = "libm", not(test)), no_std)]
+
+#[cfg(feature = "libm")]
+extern crate alloc;
+
+pub mod scalar;
+pub mod vector;
+pub mod rect;
+pub mod curve;
+pub mod transform;
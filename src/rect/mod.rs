@@ -0,0 +1,93 @@
+//===================================================================================================================================================================================//
+//
+//  /$$$$$$$                        /$$            /$$$$$$  /$$$$$$$
+// | $$__  $$                      | $$           /$$__  $$| $$__  $$
+// | $$  \ $$  /$$$$$$   /$$$$$$$ /$$$$$$        |__/  \ $$| $$  \ $$
+// | $$$$$$$/ /$$__  $$ /$$_____/|_  $$_/          /$$$$$$/| $$  | $$
+// | $$__  $$| $$$$$$$$| $$        | $$           /$$____/ | $$  | $$
+// | $$  \ $$| $$_____/| $$        | $$ /$$      | $$      | $$  | $$
+// | $$  | $$|  $$$$$$$|  $$$$$$$  |  $$$$/      | $$$$$$$$| $$$$$$$/
+// |__/  |__/ \_______/ \_______/   \___/        |________/|_______/
+//
+//===================================================================================================================================================================================//
+
+//?
+//? Created by LunaticWyrm467 and others.
+//?
+//? All code is licensed under the MIT license.
+//? Feel free to reproduce, modify, and do whatever.
+//?
+
+//!
+//! Contains the shared `Rect` trait family implemented by every concrete rect type (currently
+//! just `Rect2`), plus the `r2d` submodule holding `Rect2`'s own non-shared behaviours.
+//!
+
+#[cfg(feature = "libm")]
+use alloc::vec::Vec;
+#[cfg(feature = "libm")]
+use core::ops::Add;
+#[cfg(not(feature = "libm"))]
+use std::ops::Add;
+
+use crate::scalar::{ Scalar, SignedScalar, FloatScalar };
+
+mod r2d;
+
+pub use r2d::{ Rect2, Side2, SideOffsets2 };
+
+
+/*
+    Trait
+        Definitions
+*/
+
+
+/// An empty extension point implemented alongside `Rect` for every concrete rect type.
+pub trait RectAbstract<T: Scalar, P, R> {}
+
+/// Implements the common position/size/bounding-box surface shared by all 2D and 3D rects.
+pub trait Rect<T: Scalar, P, R, A, S> {
+    fn new(position: P, size: P) -> R;
+    fn encompass_points(points: &Vec<P>) -> R;
+    fn identity(&self) -> &R;
+    fn position(&self) -> &P;
+    fn position_mut(&mut self) -> &mut P;
+    fn size(&self) -> &P;
+    fn size_mut(&mut self) -> &mut P;
+    fn set_position(&mut self, position: P);
+    fn set_size(&mut self, size: P);
+    fn vertex(&self, idx: usize) -> P;
+    fn longest_axis(&self) -> A;
+    fn shortest_axis(&self) -> A;
+    fn axis_length(&self, axis: A) -> T;
+    fn expand_to_include(&self, point: P) -> R;
+    fn grow_side(&self, side: S, amount: T) -> R;
+    fn intersects(&self, other: &R, including_borders: bool) -> bool;
+
+    /// Returns the overlapping region between this and another rect, or `None` if they do not
+    /// overlap.
+    fn intersection(&self, other: &R) -> Option<R>;
+
+    /// Returns the smallest rect containing both this and another rect.
+    fn merge(&self, other: &R) -> R;
+
+    /// Clips this rect to lie entirely within `bounds`, shrinking it to the overlapping region.
+    /// Unlike `intersection`, this always returns a rect, collapsing to zero size where the two
+    /// rects do not overlap.
+    fn clip(&self, bounds: &R) -> R;
+
+    /// Returns whether `other` is fully contained within this rect.
+    fn contains_rect(&self, other: &R) -> bool;
+
+    /// Returns the point opposite `position()`, i.e. `position() + size()`.
+    fn end(&self) -> P where P: Add<Output = P> + Clone {
+        self.position().clone() + self.size().clone()
+    }
+}
+
+/// Adds operations that only make sense for rects over signed scalars.
+pub trait SignedRect<T: SignedScalar, P, R, A, S>: Rect<T, P, R, A, S> {}
+
+/// Adds operations that only make sense for rects over floating-point scalars.
+pub trait FloatRect<T: FloatScalar, P, R, A, S>: Rect<T, P, R, A, S> {}
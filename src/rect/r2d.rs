@@ -26,6 +26,16 @@
 use super::*;
 use crate::vector::{ Vec2, Axis2 };
 
+#[cfg(feature = "approx")]
+use approx::{ AbsDiffEq, RelativeEq };
+
+#[cfg(feature = "libm")]
+use alloc::vec::Vec;
+#[cfg(feature = "libm")]
+use core::fmt;
+#[cfg(not(feature = "libm"))]
+use std::fmt;
+
 
 /*
     2D Rect
@@ -33,6 +43,7 @@ use crate::vector::{ Vec2, Axis2 };
 */
 
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Side2 {
     Top,
@@ -41,11 +52,65 @@ pub enum Side2 {
     Right
 }
 
+/// A set of four offsets from the edges of a `Rect2`, used to inset or outset it on all sides
+/// in a single call instead of repeated `grow_side` calls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SideOffsets2<T: Scalar> {
+    pub top:    T,
+    pub right:  T,
+    pub bottom: T,
+    pub left:   T
+}
+
+impl <T: Scalar> SideOffsets2<T> {
+
+    /// Creates a new `SideOffsets2` from explicit top, right, bottom, and left amounts.
+    pub fn new(top: T, right: T, bottom: T, left: T) -> SideOffsets2<T> {
+        SideOffsets2 { top, right, bottom, left }
+    }
+
+    /// Creates a `SideOffsets2` with the same amount applied to all four sides.
+    pub fn uniform(amount: T) -> SideOffsets2<T> {
+        SideOffsets2::new(amount, amount, amount, amount)
+    }
+
+    /// Creates a `SideOffsets2` with `horizontal` applied to the left and right sides, and
+    /// `vertical` applied to the top and bottom sides.
+    pub fn symmetric(horizontal: T, vertical: T) -> SideOffsets2<T> {
+        SideOffsets2::new(vertical, horizontal, vertical, horizontal)
+    }
+}
+
 /// A 2D Rectangle with a position and size.
 /// Contains common geometric and bounding box methods.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "Rect2Repr<T>", from = "Rect2Repr<T>"))]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd)]
 pub struct Rect2<T: Scalar>(pub Vec2<T>, pub Vec2<T>);
 
+/// The wire representation of a `Rect2`, kept stable as an explicit position/size pair
+/// independent of the tuple-struct layout used internally.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Rect2Repr<T: Scalar> {
+    position: Vec2<T>,
+    size:     Vec2<T>
+}
+
+#[cfg(feature = "serde")]
+impl <T: Scalar> From<Rect2<T>> for Rect2Repr<T> {
+    fn from(rect: Rect2<T>) -> Self {
+        Rect2Repr { position: rect.0, size: rect.1 }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl <T: Scalar> From<Rect2Repr<T>> for Rect2<T> {
+    fn from(repr: Rect2Repr<T>) -> Self {
+        Rect2(repr.position, repr.size)
+    }
+}
+
 impl <T: Scalar> RectAbstract<T, Vec2<T>, Rect2<T>> for Rect2<T> {}
 
 impl <T: Scalar> Rect<T, Vec2<T>, Rect2<T>, Axis2, Side2> for Rect2<T> {
@@ -187,6 +252,42 @@ impl <T: Scalar> Rect<T, Vec2<T>, Rect2<T>, Axis2, Side2> for Rect2<T> {
 
         true
     }
+
+    fn intersection(&self, other: &Rect2<T>) -> Option<Rect2<T>> {
+        let origin: Vec2<T> = Vec2(self.x().max(other.x()), self.y().max(other.y()));
+        let end:    Vec2<T> = Vec2(self.end().x().min(other.end().x()), self.end().y().min(other.end().y()));
+
+        if end.x() <= origin.x() || end.y() <= origin.y() {
+            return None;
+        }
+
+        Some(Rect2(origin.to_owned(), end - origin))
+    }
+
+    fn merge(&self, other: &Rect2<T>) -> Rect2<T> {
+        let origin: Vec2<T> = Vec2(self.x().min(other.x()), self.y().min(other.y()));
+        let end:    Vec2<T> = Vec2(self.end().x().max(other.end().x()), self.end().y().max(other.end().y()));
+
+        Rect2(origin.to_owned(), end - origin)
+    }
+
+    fn clip(&self, bounds: &Rect2<T>) -> Rect2<T> {
+        let origin: Vec2<T> = Vec2(
+            self.x().max(bounds.x()).min(bounds.end().x()),
+            self.y().max(bounds.y()).min(bounds.end().y())
+        );
+        let end:    Vec2<T> = Vec2(
+            self.end().x().min(bounds.end().x()).max(origin.x()),
+            self.end().y().min(bounds.end().y()).max(origin.y())
+        );
+
+        Rect2(origin.to_owned(), end - origin)
+    }
+
+    fn contains_rect(&self, other: &Rect2<T>) -> bool {
+        self.x() <= other.x() && self.y() <= other.y()
+            && self.end().x() >= other.end().x() && self.end().y() >= other.end().y()
+    }
 }
 
 impl <T: SignedScalar> SignedRect<T, Vec2<T>, Rect2<T>, Axis2, Side2> for Rect2<T> {}
@@ -229,6 +330,24 @@ impl <T: Scalar> Rect2<T> {
     pub fn height(&self) -> T {
         self.1.y()
     }
+
+    /// Shrinks this `Rect2` inward by `offsets`, moving the origin in by the `left`/`top`
+    /// amounts and reducing the size by `left + right`/`top + bottom`.
+    pub fn inset(&self, offsets: SideOffsets2<T>) -> Rect2<T> {
+        Rect2(
+            Vec2(self.x() + offsets.left, self.y() + offsets.top),
+            Vec2(self.width() - (offsets.left + offsets.right), self.height() - (offsets.top + offsets.bottom))
+        )
+    }
+
+    /// Grows this `Rect2` outward by `offsets`, moving the origin out by the `left`/`top`
+    /// amounts and increasing the size by `left + right`/`top + bottom`.
+    pub fn outset(&self, offsets: SideOffsets2<T>) -> Rect2<T> {
+        Rect2(
+            Vec2(self.x() - offsets.left, self.y() - offsets.top),
+            Vec2(self.width() + (offsets.left + offsets.right), self.height() + (offsets.top + offsets.bottom))
+        )
+    }
 }
 
 
@@ -244,8 +363,213 @@ impl <T: Scalar> Default for Rect2<T> {
     }
 }
 
-impl <T: Scalar> std::fmt::Display for Rect2<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl <T: Scalar> fmt::Display for Rect2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Rect2({}, {})", self.0, self.1)
     }
+}
+
+
+/*
+    Approx
+        Equality
+*/
+
+
+/// Compares a `Rect2` to another by comparing its position and size componentwise, allowing
+/// tests to tolerate the floating-point drift produced by Bézier bounds or interpolation.
+#[cfg(feature = "approx")]
+impl <T: FloatScalar> AbsDiffEq for Rect2<T> {
+    type Epsilon = T;
+
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.position().abs_diff_eq(other.position(), epsilon) && self.size().abs_diff_eq(other.size(), epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl <T: FloatScalar> RelativeEq for Rect2<T> {
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.position().relative_eq(other.position(), epsilon, max_relative)
+            && self.size().relative_eq(other.size(), epsilon, max_relative)
+    }
+}
+
+
+/*
+    Unit
+        Tests
+*/
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intersection_returns_overlapping_region() {
+        let a: Rect2<i32> = Rect2::from_components(0, 0, 10, 10);
+        let b: Rect2<i32> = Rect2::from_components(5, 5, 10, 10);
+
+        assert_eq!(a.intersection(&b), Some(Rect2::from_components(5, 5, 5, 5)));
+    }
+
+    #[test]
+    fn intersection_is_none_when_only_touching_at_an_edge() {
+        let a: Rect2<i32> = Rect2::from_components(0, 0, 10, 10);
+        let b: Rect2<i32> = Rect2::from_components(10, 0, 10, 10);
+
+        assert_eq!(a.intersection(&b), None);
+    }
+
+    #[test]
+    fn intersection_is_none_when_disjoint() {
+        let a: Rect2<i32> = Rect2::from_components(0, 0, 10, 10);
+        let b: Rect2<i32> = Rect2::from_components(100, 100, 10, 10);
+
+        assert_eq!(a.intersection(&b), None);
+    }
+
+    #[test]
+    fn merge_returns_smallest_rect_containing_both() {
+        let a: Rect2<i32> = Rect2::from_components(0, 0, 10, 10);
+        let b: Rect2<i32> = Rect2::from_components(20, 5, 10, 10);
+
+        assert_eq!(a.merge(&b), Rect2::from_components(0, 0, 30, 15));
+    }
+
+    #[test]
+    fn clip_shrinks_to_the_overlapping_region() {
+        let a: Rect2<i32>      = Rect2::from_components(0, 0, 10, 10);
+        let bounds: Rect2<i32> = Rect2::from_components(5, 5, 10, 10);
+
+        assert_eq!(a.clip(&bounds), Rect2::from_components(5, 5, 5, 5));
+    }
+
+    #[test]
+    fn clip_collapses_to_zero_size_when_disjoint() {
+        let a: Rect2<i32>      = Rect2::from_components(0, 0, 10, 10);
+        let bounds: Rect2<i32> = Rect2::from_components(100, 100, 10, 10);
+        let clipped: Rect2<i32> = a.clip(&bounds);
+
+        assert_eq!(clipped.width(), 0);
+        assert_eq!(clipped.height(), 0);
+    }
+
+    #[test]
+    fn contains_rect_is_true_for_a_fully_enclosed_rect() {
+        let outer: Rect2<i32> = Rect2::from_components(0, 0, 10, 10);
+        let inner: Rect2<i32> = Rect2::from_components(2, 2, 5, 5);
+
+        assert!(outer.contains_rect(&inner));
+        assert!(!inner.contains_rect(&outer));
+    }
+
+    #[test]
+    fn contains_rect_is_false_when_partially_outside() {
+        let a: Rect2<i32> = Rect2::from_components(0, 0, 10, 10);
+        let b: Rect2<i32> = Rect2::from_components(5, 5, 10, 10);
+
+        assert!(!a.contains_rect(&b));
+    }
+
+    #[test]
+    fn side_offsets_uniform_applies_the_same_amount_to_all_sides() {
+        let offsets: SideOffsets2<i32> = SideOffsets2::uniform(2);
+
+        assert_eq!(offsets, SideOffsets2::new(2, 2, 2, 2));
+    }
+
+    #[test]
+    fn side_offsets_symmetric_applies_horizontal_and_vertical_amounts() {
+        let offsets: SideOffsets2<i32> = SideOffsets2::symmetric(3, 4);
+
+        assert_eq!(offsets, SideOffsets2::new(4, 3, 4, 3));
+    }
+
+    #[test]
+    fn inset_shrinks_the_rect_by_the_given_offsets() {
+        let rect:    Rect2<i32> = Rect2::from_components(0, 0, 10, 10);
+        let inset:   Rect2<i32> = rect.inset(SideOffsets2::uniform(2));
+
+        assert_eq!(inset, Rect2::from_components(2, 2, 6, 6));
+    }
+
+    #[test]
+    fn outset_grows_the_rect_by_the_given_offsets() {
+        let rect:    Rect2<i32> = Rect2::from_components(2, 2, 6, 6);
+        let outset:  Rect2<i32> = rect.outset(SideOffsets2::uniform(2));
+
+        assert_eq!(outset, Rect2::from_components(0, 0, 10, 10));
+    }
+
+    #[test]
+    fn inset_and_outset_by_the_same_offsets_round_trip() {
+        let rect:    Rect2<i32>        = Rect2::from_components(0, 0, 10, 10);
+        let offsets: SideOffsets2<i32> = SideOffsets2::new(1, 2, 3, 4);
+
+        assert_eq!(rect.inset(offsets).outset(offsets), rect);
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn abs_diff_eq_tolerates_drift_within_epsilon() {
+        let a: Rect2<f64> = Rect2::from_components(0.0, 0.0, 10.0, 10.0);
+        let b: Rect2<f64> = Rect2::from_components(1e-12, 0.0, 10.0, 10.0 - 1e-12);
+
+        assert!(a.abs_diff_eq(&b, f64::default_epsilon()));
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn abs_diff_eq_rejects_drift_beyond_epsilon() {
+        let a: Rect2<f64> = Rect2::from_components(0.0, 0.0, 10.0, 10.0);
+        let b: Rect2<f64> = Rect2::from_components(0.1, 0.0, 10.0, 10.0);
+
+        assert!(!a.abs_diff_eq(&b, f64::default_epsilon()));
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn relative_eq_holds_for_equal_rects_at_large_magnitude() {
+        let a: Rect2<f64> = Rect2::from_components(10_000.0, 10_000.0, 500.0, 500.0);
+        let b: Rect2<f64> = a.clone();
+
+        assert!(a.relative_eq(&b, f64::default_epsilon(), f64::default_max_relative()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn rect2_round_trips_through_json() {
+        let rect: Rect2<i32> = Rect2::from_components(1, 2, 3, 4);
+        let json: String     = serde_json::to_string(&rect).unwrap();
+
+        assert_eq!(serde_json::from_str::<Rect2<i32>>(&json).unwrap(), rect);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn rect2_serializes_as_a_position_size_pair() {
+        let rect: Rect2<i32> = Rect2::from_components(1, 2, 3, 4);
+        let json: String     = serde_json::to_string(&rect).unwrap();
+
+        assert_eq!(json, r#"{"position":[1,2],"size":[3,4]}"#);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn side2_round_trips_through_json() {
+        for side in [Side2::Top, Side2::Bottom, Side2::Left, Side2::Right] {
+            let json: String = serde_json::to_string(&side).unwrap();
+            assert_eq!(serde_json::from_str::<Side2>(&json).unwrap(), side);
+        }
+    }
 }
\ No newline at end of file
@@ -1,6 +1,13 @@
 use approx::{ RelativeEq, AbsDiffEq };
 use num_traits::{ Num, Signed, Float, FloatConst, PrimInt, NumCast };
 
+#[cfg(feature = "libm")]
+use core::fmt;
+#[cfg(not(feature = "libm"))]
+use std::fmt;
+
+pub(crate) mod ops;
+
 
 /*
     Trait
@@ -9,7 +16,7 @@ use num_traits::{ Num, Signed, Float, FloatConst, PrimInt, NumCast };
 
 
 /// Implements common behaviours and additional operations for all primitives.
-pub trait Scalar: Clone + Copy + Num + Default + PartialOrd + std::fmt::Display + std::fmt::Debug + NumCast {
+pub trait Scalar: Clone + Copy + Num + Default + PartialOrd + fmt::Display + fmt::Debug + NumCast {
 
     /// Returns the minimum value of this value and another.
     /// This is implemented manually to not rely on the Ord trait.
@@ -138,7 +145,7 @@ pub trait IntUnique<T: IntScalar<T>> {
 */
 
 
-impl <T: Clone + Copy + Num + Default + PartialOrd + std::fmt::Display + std::fmt::Debug + NumCast> Scalar for T {}
+impl <T: Clone + Copy + Num + Default + PartialOrd + fmt::Display + fmt::Debug + NumCast> Scalar for T {}
 impl <T: Scalar + Ord + PrimInt + IntUnique<T>> IntScalar<T> for T {}
 impl <T: Scalar + Signed> SignedScalar for T {}
 impl <T: SignedScalar + Float + FloatConst + RelativeEq + AbsDiffEq<Epsilon = Self>> FloatScalar for T {}
@@ -0,0 +1,97 @@
+//===================================================================================================================================================================================//
+//
+//  /$$$$$$$                        /$$            /$$$$$$  /$$$$$$$
+// | $$__  $$                      | $$           /$$__  $$| $$__  $$
+// | $$  \ $$  /$$$$$$   /$$$$$$$ /$$$$$$        |__/  \ $$| $$  \ $$
+// | $$$$$$$/ /$$__  $$ /$$_____/|_  $$_/          /$$$$$$/| $$  | $$
+// | $$__  $$| $$$$$$$$| $$        | $$           /$$____/ | $$  | $$
+// | $$  \ $$| $$_____/| $$        | $$ /$$      | $$      | $$  | $$
+// | $$  | $$|  $$$$$$$|  $$$$$$$  |  $$$$/      | $$$$$$$$| $$$$$$$/
+// |__/  |__/ \_______/ \_______/   \___/        |________/|_______/
+//
+//===================================================================================================================================================================================//
+
+//?
+//? Created by LunaticWyrm467 and others.
+//?
+//? All code is licensed under the MIT license.
+//? Feel free to reproduce, modify, and do whatever.
+//?
+
+//!
+//! A private submodule for the scalar module that routes every floating-point primitive used
+//! internally by the crate through either `num_traits::Float` or `libm`, depending on whether
+//! the `libm` feature is enabled. Bézier sampling, cubic interpolation, and any trig-based
+//! vector math should call through here rather than invoking `Float` methods directly, so that
+//! enabling `libm` makes the crate's geometry bit-reproducible across platforms and lets it
+//! build `no_std`.
+//!
+//! Each primitive takes a plain `num_traits::Float` bound (which already implies `NumCast`)
+//! rather than a `libm`-specific bound, so that
+//! turning the `libm` feature on or off never changes what generic callers have to satisfy:
+//! under `libm` the value is round-tripped through `f64` and back via `NumCast`.
+//!
+
+use num_traits::{ Float, NumCast };
+
+
+/*
+    Float
+        Primitives
+*/
+
+
+/// Returns the sine of `x`, given in radians.
+#[cfg(not(feature = "libm"))]
+pub(crate) fn sin<T: Float>(x: T) -> T {
+    x.sin()
+}
+
+/// Returns the sine of `x`, given in radians, computed deterministically via `libm`.
+#[cfg(feature = "libm")]
+pub(crate) fn sin<T: Float + NumCast>(x: T) -> T {
+    via_f64(x, libm::sin)
+}
+
+/// Returns the cosine of `x`, given in radians.
+#[cfg(not(feature = "libm"))]
+pub(crate) fn cos<T: Float>(x: T) -> T {
+    x.cos()
+}
+
+/// Returns the cosine of `x`, given in radians, computed deterministically via `libm`.
+#[cfg(feature = "libm")]
+pub(crate) fn cos<T: Float + NumCast>(x: T) -> T {
+    via_f64(x, libm::cos)
+}
+
+/// Returns the square root of `x`.
+#[cfg(not(feature = "libm"))]
+pub(crate) fn sqrt<T: Float>(x: T) -> T {
+    x.sqrt()
+}
+
+/// Returns the square root of `x`, computed deterministically via `libm`.
+#[cfg(feature = "libm")]
+pub(crate) fn sqrt<T: Float + NumCast>(x: T) -> T {
+    via_f64(x, libm::sqrt)
+}
+
+/// Returns the absolute value of `x`.
+#[cfg(not(feature = "libm"))]
+pub(crate) fn abs<T: Float>(x: T) -> T {
+    x.abs()
+}
+
+/// Returns the absolute value of `x`, computed deterministically via `libm`.
+#[cfg(feature = "libm")]
+pub(crate) fn abs<T: Float + NumCast>(x: T) -> T {
+    via_f64(x, libm::fabs)
+}
+
+/// Converts `x` to `f64`, applies the given `libm` primitive, and converts the result back to
+/// `T`, so that callers need only `T: Float + NumCast` regardless of `x`'s native width.
+#[cfg(feature = "libm")]
+fn via_f64<T: Float + NumCast>(x: T, f: fn(f64) -> f64) -> T {
+    T::from(f(x.to_f64().unwrap())).unwrap()
+}
@@ -0,0 +1,231 @@
+//===================================================================================================================================================================================//
+//
+//  /$$$$$$$                        /$$            /$$$$$$  /$$$$$$$
+// | $$__  $$                      | $$           /$$__  $$| $$__  $$
+// | $$  \ $$  /$$$$$$   /$$$$$$$ /$$$$$$        |__/  \ $$| $$  \ $$
+// | $$$$$$$/ /$$__  $$ /$$_____/|_  $$_/          /$$$$$$/| $$  | $$
+// | $$__  $$| $$$$$$$$| $$        | $$           /$$____/ | $$  | $$
+// | $$  \ $$| $$_____/| $$        | $$ /$$      | $$      | $$  | $$
+// | $$  | $$|  $$$$$$$|  $$$$$$$  |  $$$$/      | $$$$$$$$| $$$$$$$/
+// |__/  |__/ \_______/ \_______/   \___/        |________/|_______/
+//
+//===================================================================================================================================================================================//
+
+//?
+//? Created by LunaticWyrm467 and others.
+//?
+//? All code is licensed under the MIT license.
+//? Feel free to reproduce, modify, and do whatever.
+//?
+
+//!
+//! Contains `Transform2D`, an affine 2×3 matrix that maps `Vec2` points and `Rect2` bounds
+//! through translation, rotation, scale, and skew.
+//!
+
+#[cfg(feature = "libm")]
+use alloc::vec::Vec;
+
+use crate::scalar::FloatScalar;
+use crate::scalar::ops;
+use crate::vector::Vec2;
+use crate::rect::{ Rect, Rect2 };
+
+
+/*
+    Transform 2D
+        Implementation
+*/
+
+
+/// An affine 2×3 matrix of the form:
+///
+/// ```text
+/// | a  c  tx |
+/// | b  d  ty |
+/// ```
+///
+/// mapping a point `(x, y)` to `(a*x + c*y + tx, b*x + d*y + ty)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform2D<T: FloatScalar> {
+    pub a:  T,
+    pub b:  T,
+    pub c:  T,
+    pub d:  T,
+    pub tx: T,
+    pub ty: T
+}
+
+impl <T: FloatScalar> Transform2D<T> {
+
+    /// Creates a new `Transform2D` from its raw matrix components.
+    pub fn new(a: T, b: T, c: T, d: T, tx: T, ty: T) -> Transform2D<T> {
+        Transform2D { a, b, c, d, tx, ty }
+    }
+
+    /// Returns the identity transform, which maps every point and rect to itself.
+    pub fn identity() -> Transform2D<T> {
+        Transform2D::new(T::one(), T::zero(), T::zero(), T::one(), T::zero(), T::zero())
+    }
+
+    /// Creates a transform that translates by `offset`.
+    pub fn translation(offset: Vec2<T>) -> Transform2D<T> {
+        Transform2D::new(T::one(), T::zero(), T::zero(), T::one(), offset.x(), offset.y())
+    }
+
+    /// Creates a transform that rotates counter-clockwise by `angle` radians.
+    pub fn rotation(angle: T) -> Transform2D<T> {
+        let sin: T = ops::sin(angle);
+        let cos: T = ops::cos(angle);
+        Transform2D::new(cos, sin, -sin, cos, T::zero(), T::zero())
+    }
+
+    /// Creates a transform that scales independently along each axis by `factors`.
+    pub fn scale(factors: Vec2<T>) -> Transform2D<T> {
+        Transform2D::new(factors.x(), T::zero(), T::zero(), factors.y(), T::zero(), T::zero())
+    }
+
+    /// Composes this transform with `other`, applying this transform first and `other` second.
+    pub fn then(&self, other: &Transform2D<T>) -> Transform2D<T> {
+        Self::combine(self, other)
+    }
+
+    /// Composes this transform with `other`, applying `other` first and this transform second.
+    pub fn pre_transform(&self, other: &Transform2D<T>) -> Transform2D<T> {
+        Self::combine(other, self)
+    }
+
+    /// Returns the inverse of this transform, or `None` if it is not invertible (its
+    /// determinant is zero).
+    pub fn inverse(&self) -> Option<Transform2D<T>> {
+        let det: T = self.a * self.d - self.b * self.c;
+        if det == T::zero() {
+            return None;
+        }
+
+        let a: T = self.d / det;
+        let b: T = -self.b / det;
+        let c: T = -self.c / det;
+        let d: T = self.a / det;
+        let tx: T = -(a * self.tx + c * self.ty);
+        let ty: T = -(b * self.tx + d * self.ty);
+
+        Some(Transform2D::new(a, b, c, d, tx, ty))
+    }
+
+    /// Maps a `Vec2` point through this transform.
+    pub fn transform_point(&self, point: Vec2<T>) -> Vec2<T> {
+        Vec2(
+            self.a * point.x() + self.c * point.y() + self.tx,
+            self.b * point.x() + self.d * point.y() + self.ty
+        )
+    }
+
+    /// Maps a `Rect2` through this transform by transforming all four corners and rebuilding
+    /// the axis-aligned bounding result, so that arbitrary rotations still yield a valid
+    /// axis-aligned `Rect2`.
+    pub fn transform_rect(&self, rect: &Rect2<T>) -> Rect2<T> {
+        let corners: Vec<Vec2<T>> = (0..4).map(|idx| self.transform_point(rect.vertex(idx))).collect();
+        Rect2::encompass_points(&corners)
+    }
+
+    /// Combines `first` and `second`, where `first` is applied before `second`.
+    fn combine(first: &Transform2D<T>, second: &Transform2D<T>) -> Transform2D<T> {
+        Transform2D::new(
+            second.a * first.a + second.c * first.b,
+            second.b * first.a + second.d * first.b,
+            second.a * first.c + second.c * first.d,
+            second.b * first.c + second.d * first.d,
+            second.a * first.tx + second.c * first.ty + second.tx,
+            second.b * first.tx + second.d * first.ty + second.ty
+        )
+    }
+}
+
+impl <T: FloatScalar> Default for Transform2D<T> {
+    fn default() -> Self {
+        Transform2D::identity()
+    }
+}
+
+
+/*
+    Unit
+        Tests
+*/
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn identity_leaves_points_unchanged() {
+        let point: Vec2<f64> = Vec2(3.0, -4.0);
+        assert_relative_eq!(Transform2D::identity().transform_point(point.clone()), point);
+    }
+
+    #[test]
+    fn translation_offsets_points() {
+        let transform: Transform2D<f64> = Transform2D::translation(Vec2(1.0, 2.0));
+        assert_relative_eq!(transform.transform_point(Vec2(3.0, 4.0)), Vec2(4.0, 6.0));
+    }
+
+    #[test]
+    fn scale_multiplies_each_axis_independently() {
+        let transform: Transform2D<f64> = Transform2D::scale(Vec2(2.0, 3.0));
+        assert_relative_eq!(transform.transform_point(Vec2(3.0, 4.0)), Vec2(6.0, 12.0));
+    }
+
+    #[test]
+    fn rotation_by_a_quarter_turn_maps_x_axis_to_y_axis() {
+        let transform: Transform2D<f64> = Transform2D::rotation(std::f64::consts::FRAC_PI_2);
+        assert_relative_eq!(transform.transform_point(Vec2(1.0, 0.0)), Vec2(0.0, 1.0), epsilon = 1e-10);
+    }
+
+    #[test]
+    fn then_applies_self_before_other() {
+        let translate: Transform2D<f64> = Transform2D::translation(Vec2(1.0, 0.0));
+        let scale:     Transform2D<f64> = Transform2D::scale(Vec2(2.0, 2.0));
+
+        // Translate first, then scale: (0, 0) -> (1, 0) -> (2, 0).
+        assert_relative_eq!(translate.then(&scale).transform_point(Vec2(0.0, 0.0)), Vec2(2.0, 0.0));
+    }
+
+    #[test]
+    fn pre_transform_applies_other_before_self() {
+        let translate: Transform2D<f64> = Transform2D::translation(Vec2(1.0, 0.0));
+        let scale:     Transform2D<f64> = Transform2D::scale(Vec2(2.0, 2.0));
+
+        // Scale first, then translate: (1, 0) -> (2, 0) -> (3, 0).
+        assert_relative_eq!(translate.pre_transform(&scale).transform_point(Vec2(1.0, 0.0)), Vec2(3.0, 0.0));
+    }
+
+    #[test]
+    fn inverse_undoes_the_transform() {
+        let transform: Transform2D<f64> = Transform2D::rotation(0.7).then(&Transform2D::translation(Vec2(5.0, -2.0)));
+        let inverse:   Transform2D<f64> = transform.inverse().unwrap();
+
+        let point:      Vec2<f64> = Vec2(3.0, 4.0);
+        let round_trip: Vec2<f64> = inverse.transform_point(transform.transform_point(point.clone()));
+
+        assert_relative_eq!(round_trip, point, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn inverse_is_none_for_a_singular_transform() {
+        let singular: Transform2D<f64> = Transform2D::scale(Vec2(0.0, 1.0));
+        assert_eq!(singular.inverse(), None);
+    }
+
+    #[test]
+    fn transform_rect_rebuilds_an_axis_aligned_bounding_box() {
+        let rect:      Rect2<f64>     = Rect2::from_components(0.0, 0.0, 2.0, 2.0);
+        let transform: Transform2D<f64> = Transform2D::rotation(std::f64::consts::FRAC_PI_2);
+        let result:    Rect2<f64>     = transform.transform_rect(&rect);
+
+        assert_relative_eq!(result.position(), &Vec2(-2.0, 0.0), epsilon = 1e-10);
+        assert_relative_eq!(result.size(), &Vec2(2.0, 2.0), epsilon = 1e-10);
+    }
+}
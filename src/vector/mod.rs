@@ -0,0 +1,251 @@
+//===================================================================================================================================================================================//
+//
+//  /$$$$$$$                        /$$            /$$$$$$  /$$$$$$$
+// | $$__  $$                      | $$           /$$__  $$| $$__  $$
+// | $$  \ $$  /$$$$$$   /$$$$$$$ /$$$$$$        |__/  \ $$| $$  \ $$
+// | $$$$$$$/ /$$__  $$ /$$_____/|_  $$_/          /$$$$$$/| $$  | $$
+// | $$__  $$| $$$$$$$$| $$        | $$           /$$____/ | $$  | $$
+// | $$  \ $$| $$_____/| $$        | $$ /$$      | $$      | $$  | $$
+// | $$  | $$|  $$$$$$$|  $$$$$$$  |  $$$$/      | $$$$$$$$| $$$$$$$/
+// |__/  |__/ \_______/ \_______/   \___/        |________/|_______/
+//
+//===================================================================================================================================================================================//
+
+//?
+//? Created by LunaticWyrm467 and others.
+//?
+//? All code is licensed under the MIT license.
+//? Feel free to reproduce, modify, and do whatever.
+//?
+
+//!
+//! Contains the 2D vector type and its supporting axis enum, shared by the `rect`, `curve`,
+//! and `transform` modules.
+//!
+
+#[cfg(feature = "libm")]
+use core::{ fmt, ops::{ Add, Sub } };
+#[cfg(not(feature = "libm"))]
+use std::{ fmt, ops::{ Add, Sub } };
+
+use crate::scalar::{ Scalar, FloatScalar };
+
+#[cfg(feature = "approx")]
+use approx::{ AbsDiffEq, RelativeEq };
+
+
+/*
+    Axis 2D
+        Implementation
+*/
+
+
+/// Identifies one axis (or neither) of 2D space.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis2 {
+    X,
+    Y,
+    None
+}
+
+
+/*
+    Vec2
+        Implementation
+*/
+
+
+/// A 2D vector with an `x` and `y` component.
+/// Contains common component accessors and axis-aligned helpers.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd)]
+pub struct Vec2<T: Scalar>(pub T, pub T);
+
+impl <T: Scalar> Vec2<T> {
+
+    /// Returns the x component of this `Vec2`.
+    pub fn x(&self) -> T {
+        self.0
+    }
+
+    /// Returns the y component of this `Vec2`.
+    pub fn y(&self) -> T {
+        self.1
+    }
+
+    /// Sets the x component of this `Vec2`.
+    pub fn set_x(&mut self, x: T) {
+        self.0 = x;
+    }
+
+    /// Sets the y component of this `Vec2`.
+    pub fn set_y(&mut self, y: T) {
+        self.1 = y;
+    }
+
+    /// Returns a `Vec2` with this vector's x component and a zeroed y component.
+    pub fn of_x(&self) -> Vec2<T> {
+        Vec2(self.0, T::zero())
+    }
+
+    /// Returns a `Vec2` with this vector's y component and a zeroed x component.
+    pub fn of_y(&self) -> Vec2<T> {
+        Vec2(T::zero(), self.1)
+    }
+
+    /// Creates a `Vec2` with the given x component and a zeroed y component.
+    pub fn on_x(x: T) -> Vec2<T> {
+        Vec2(x, T::zero())
+    }
+
+    /// Creates a `Vec2` with the given y component and a zeroed x component.
+    pub fn on_y(y: T) -> Vec2<T> {
+        Vec2(T::zero(), y)
+    }
+
+    /// Converts a `Vec2` to a `Vec2` of a different type.
+    pub fn cast<U: Scalar>(&self) -> Vec2<U> {
+        Vec2(U::from(self.0).unwrap(), U::from(self.1).unwrap())
+    }
+}
+
+
+/*
+    Global
+        Behaviours
+*/
+
+
+impl <T: Scalar> Default for Vec2<T> {
+    fn default() -> Self {
+        Vec2(T::zero(), T::zero())
+    }
+}
+
+impl <T: Scalar> fmt::Display for Vec2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.0, self.1)
+    }
+}
+
+impl <T: Scalar> Add<Vec2<T>> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn add(self, rhs: Vec2<T>) -> Vec2<T> {
+        Vec2(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl <T: Scalar> Sub<Vec2<T>> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, rhs: Vec2<T>) -> Vec2<T> {
+        Vec2(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl <'a, T: Scalar> Add<&'a Vec2<T>> for &'a Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn add(self, rhs: &'a Vec2<T>) -> Vec2<T> {
+        Vec2(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl <'a, T: Scalar> Sub<&'a Vec2<T>> for &'a Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, rhs: &'a Vec2<T>) -> Vec2<T> {
+        Vec2(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+
+/*
+    Approx
+        Equality
+*/
+
+
+#[cfg(feature = "approx")]
+impl <T: FloatScalar> AbsDiffEq for Vec2<T> {
+    type Epsilon = T;
+
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        T::abs_diff_eq(&self.x(), &other.x(), epsilon) && T::abs_diff_eq(&self.y(), &other.y(), epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl <T: FloatScalar> RelativeEq for Vec2<T> {
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        T::relative_eq(&self.x(), &other.x(), epsilon, max_relative)
+            && T::relative_eq(&self.y(), &other.y(), epsilon, max_relative)
+    }
+}
+
+
+/*
+    Unit
+        Tests
+*/
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_and_sub_are_componentwise() {
+        let a: Vec2<i32> = Vec2(1, 2);
+        let b: Vec2<i32> = Vec2(3, 4);
+
+        assert_eq!(a.clone() + b.clone(), Vec2(4, 6));
+        assert_eq!(b - a, Vec2(2, 2));
+    }
+
+    #[test]
+    fn ref_add_and_sub_match_owned() {
+        let a: Vec2<i32> = Vec2(1, 2);
+        let b: Vec2<i32> = Vec2(3, 4);
+
+        assert_eq!(&a + &b, a.clone() + b.clone());
+        assert_eq!(&b - &a, b - a);
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn abs_diff_eq_tolerates_drift_within_epsilon() {
+        let a: Vec2<f64> = Vec2(1.0, 2.0);
+        let b: Vec2<f64> = Vec2(1.0 + 1e-12, 2.0 - 1e-12);
+
+        assert!(a.abs_diff_eq(&b, f64::default_epsilon()));
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn abs_diff_eq_rejects_drift_beyond_epsilon() {
+        let a: Vec2<f64> = Vec2(1.0, 2.0);
+        let b: Vec2<f64> = Vec2(1.1, 2.0);
+
+        assert!(!a.abs_diff_eq(&b, f64::default_epsilon()));
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn relative_eq_holds_for_equal_values_at_large_magnitude() {
+        let a: Vec2<f64> = Vec2(10_000.0, -10_000.0);
+        let b: Vec2<f64> = Vec2(a.x(), a.y());
+
+        assert!(a.relative_eq(&b, f64::default_epsilon(), f64::default_max_relative()));
+    }
+}